@@ -0,0 +1,24 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+/// Folds an arbitrary number of field elements into a single challenge value.
+///
+/// This is the toy crate's only "hash": it lets a signature scheme bind a
+/// challenge to more than just the raw message (for instance a commitment
+/// point's coordinates and a public key), which `ECDSA::hash`'s single-value
+/// identity hash cannot do.
+///
+/// # Examples
+///
+/// ```rust
+/// use algebra::hash;
+///
+/// fn main() {
+///     let e = hash::hash(&[3, 5, 7]);
+///     println!("{}", e);
+/// }
+/// ```
+///
+pub fn hash(values: &[i8]) -> i8 {
+    values.iter().fold(0i8, |acc, &v| acc ^ v)
+}
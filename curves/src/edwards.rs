@@ -0,0 +1,112 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::arith;
+
+/// A small twisted Edwards analogue of the short-Weierstrass `clockcurve`:
+/// ```text
+/// a*x^2 + y^2 = 1 + d*x^2*y^2  (mod p)
+/// ```
+/// Unlike `clockcurve::scalar_add`, the Edwards addition law is complete: it
+/// needs no special case for doubling or for the point at infinity (the
+/// identity is the ordinary affine point `(0, 1)`).
+#[derive(Debug, Clone, Copy)]
+pub struct Edwards {
+    pub p: i8,
+    pub a: i8,
+    pub d: i8,
+    /// Order of the group generated by `base()`.
+    pub n: i8,
+}
+
+impl Default for Edwards {
+    fn default() -> Self {
+        // A tiny twisted Edwards curve picked for teaching, at the same i8
+        // scale as `clockcurve`: -x^2 + y^2 = 1 + 2*x^2*y^2 (mod 101), whose
+        // base point generates the full 108-element group.
+        Edwards {
+            p: 101,
+            a: -1,
+            d: 2,
+            n: 108,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i8,
+    pub y: i8,
+}
+
+/// The curve's identity element: the unique fixed point of addition.
+pub const IDENTITY: Point = Point { x: 0, y: 1 };
+
+fn neg(v: i8, m: i8) -> i8 {
+    arith::mod_mul(v, m - 1, m)
+}
+
+impl Edwards {
+    /// Base point generating the full 108-element group for the default
+    /// curve parameters.
+    pub fn base(&self) -> Point {
+        Point { x: 24, y: 33 }
+    }
+
+    /// Unified point addition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use curves::edwards;
+    ///
+    /// fn main() {
+    ///     let curve = edwards::Edwards::default();
+    ///     let b = curve.base();
+    ///     let doubled = curve.scalar_add(b, b);
+    ///     println!("{:?}", doubled);
+    /// }
+    /// ```
+    pub fn scalar_add(&self, p1: Point, p2: Point) -> Point {
+        let m = self.p;
+        let x1y2 = arith::mod_mul(p1.x, p2.y, m);
+        let y1x2 = arith::mod_mul(p1.y, p2.x, m);
+        let y1y2 = arith::mod_mul(p1.y, p2.y, m);
+        let x1x2 = arith::mod_mul(p1.x, p2.x, m);
+        let dxxyy = arith::mod_mul(self.d, arith::mod_mul(x1x2, y1y2, m), m);
+        let ax1x2 = arith::mod_mul(self.a, x1x2, m);
+
+        let x3 = arith::mod_div(arith::mod_add(x1y2, y1x2, m), arith::mod_add(1, dxxyy, m), m);
+        let y3 = arith::mod_div(
+            arith::mod_add(y1y2, neg(ax1x2, m), m),
+            arith::mod_add(1, neg(dxxyy, m), m),
+            m,
+        );
+        Point { x: x3, y: y3 }
+    }
+
+    /// Order of the group generated by `base()`.
+    pub fn order(&self) -> i8 {
+        self.n
+    }
+
+    /// Scalar multiplication of an arbitrary point via double-and-add.
+    pub fn scalar_mul(&self, point: Point, k: i8) -> Point {
+        let mut result = IDENTITY;
+        let mut addend = point;
+        let mut k = k.rem_euclid(self.n);
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.scalar_add(result, addend);
+            }
+            addend = self.scalar_add(addend, addend);
+            k >>= 1;
+        }
+        result
+    }
+
+    /// Scalar multiplication of the curve's base point.
+    pub fn scalar_basemul(&self, k: i8) -> Point {
+        self.scalar_mul(self.base(), k)
+    }
+}
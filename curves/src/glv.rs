@@ -0,0 +1,307 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+/// A tiny short-Weierstrass curve `y^2 = x^3 + b (mod p)` with `a = 0`, the
+/// family the GLV method applies to (secp256k1 is the real-world instance).
+/// Unlike the crate's main `clockcurve`, `a = 0` here is load-bearing: it is
+/// exactly what makes `phi(x, y) = (beta*x mod p, y)` an endomorphism, so
+/// this module ships its own curve rather than grafting GLV onto a curve
+/// that can't support it.
+#[derive(Debug, Clone, Copy)]
+pub struct GlvCurve {
+    pub p: i8,
+    pub b: i8,
+}
+
+impl Default for GlvCurve {
+    fn default() -> Self {
+        // y^2 = x^3 + 4 (mod 13): a 21-point subgroup admitting the
+        // endomorphism below.
+        GlvCurve { p: 13, b: 4 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i8,
+    pub y: i8,
+    pub infinity: bool,
+}
+
+pub const INFINITY: Point = Point {
+    x: 0,
+    y: 0,
+    infinity: true,
+};
+
+fn modinv(a: i8, p: i8) -> i8 {
+    let (mut old_r, mut r) = (a as i32, p as i32);
+    let (mut old_s, mut s) = (1i32, 0i32);
+    while r != 0 {
+        let q = old_r.div_euclid(r);
+        let tmp_r = old_r - q * r;
+        old_r = r;
+        r = tmp_r;
+        let tmp_s = old_s - q * s;
+        old_s = s;
+        s = tmp_s;
+    }
+    old_s.rem_euclid(p as i32) as i8
+}
+
+fn field_mul(a: i8, b: i8, p: i8) -> i8 {
+    ((a as i32 * b as i32).rem_euclid(p as i32)) as i8
+}
+
+fn field_add(a: i8, b: i8, p: i8) -> i8 {
+    ((a as i32 + b as i32).rem_euclid(p as i32)) as i8
+}
+
+fn field_sub(a: i8, b: i8, p: i8) -> i8 {
+    field_add(a, field_mul(b, -1, p), p)
+}
+
+impl GlvCurve {
+    /// Generator of the curve's 21-point subgroup for the default parameters.
+    pub fn base(&self) -> Point {
+        Point {
+            x: 2,
+            y: 5,
+            infinity: false,
+        }
+    }
+
+    /// The baseline this module's GLV path is checked against: ordinary
+    /// short-Weierstrass point addition.
+    pub fn scalar_add(&self, p1: Point, p2: Point) -> Point {
+        if p1.infinity {
+            return p2;
+        }
+        if p2.infinity {
+            return p1;
+        }
+        let p = self.p;
+        if p1.x == p2.x && field_add(p1.y, p2.y, p) == 0 {
+            return INFINITY;
+        }
+
+        let lambda = if p1.x == p2.x && p1.y == p2.y {
+            if p1.y == 0 {
+                return INFINITY;
+            }
+            // (3*x1^2) / (2*y1)
+            field_mul(
+                field_mul(3, field_mul(p1.x, p1.x, p), p),
+                modinv(field_mul(2, p1.y, p), p),
+                p,
+            )
+        } else {
+            // (y2 - y1) / (x2 - x1)
+            field_mul(
+                field_sub(p2.y, p1.y, p),
+                modinv(field_sub(p2.x, p1.x, p), p),
+                p,
+            )
+        };
+
+        let x3 = field_sub(field_sub(field_mul(lambda, lambda, p), p1.x, p), p2.x, p);
+        let y3 = field_sub(field_mul(lambda, field_sub(p1.x, x3, p), p), p1.y, p);
+        Point {
+            x: x3,
+            y: y3,
+            infinity: false,
+        }
+    }
+
+    /// Ordinary double-and-add scalar multiplication; the reference
+    /// implementation `scalar_mul_glv` is checked against.
+    pub fn scalar_mul(&self, point: Point, k: i8, n: i8) -> Point {
+        let mut result = INFINITY;
+        let mut addend = point;
+        let mut k = k.rem_euclid(n);
+        while k > 0 {
+            if k & 1 == 1 {
+                result = self.scalar_add(result, addend);
+            }
+            addend = self.scalar_add(addend, addend);
+            k >>= 1;
+        }
+        result
+    }
+}
+
+/// Gauss-reduced lattice basis for splitting a scalar `k` into two short
+/// scalars `k1, k2` such that `k = k1 + k2*lambda (mod n)`, where `lambda`
+/// is the eigenvalue on the subgroup of the curve endomorphism
+/// `phi(x, y) = (beta*x mod p, y)` (`lambda^3 = 1 mod n`). Mirrors the GLV
+/// split libsecp256k1 uses to halve the width of `scalar_mul`'s
+/// double-and-add ladder.
+#[derive(Debug, Clone, Copy)]
+pub struct GlvParams {
+    pub p: i8,
+    pub beta: i8,
+    pub lambda: i8,
+    pub a1: i8,
+    pub b1: i8,
+    pub a2: i8,
+    pub b2: i8,
+}
+
+fn cube_root_of_unity(modulus: i8) -> Option<i8> {
+    (2..modulus).find(|&candidate| {
+        ((candidate as i32).pow(3)).rem_euclid(modulus as i32) == 1
+    })
+}
+
+/// Runs the Euclidean algorithm on `(n, lambda)` to find a short lattice
+/// basis `(a1, b1), (a2, b2)` with `a_i + b_i*lambda ≡ 0 (mod n)`, following
+/// GECC Algorithm 3.74's "balanced length-two representation" construction.
+fn reduce_lattice(n: i8, lambda: i8) -> (i8, i8, i8, i8) {
+    let n32 = n as i32;
+    let mut r = vec![n32, lambda as i32];
+    let mut t = vec![0i32, 1i32];
+    while *r.last().unwrap() != 0 {
+        let len = r.len();
+        let q = r[len - 2].div_euclid(r[len - 1]);
+        r.push(r[len - 2] - q * r[len - 1]);
+        t.push(t[len - 2] - q * t[len - 1]);
+    }
+
+    let sqrt_n = (n32 as f64).sqrt();
+    let l = (0..r.len())
+        .rev()
+        .find(|&i| (r[i] as f64) >= sqrt_n)
+        .unwrap_or(0);
+
+    let (a1, b1) = (r[l + 1], -t[l + 1]);
+    let norm = |a: i32, b: i32| a * a + b * b;
+    let (a2, b2) = if norm(r[l], t[l]) <= norm(r[l + 2], t[l + 2]) {
+        (r[l], -t[l])
+    } else {
+        (r[l + 2], -t[l + 2])
+    };
+
+    (a1 as i8, b1 as i8, a2 as i8, b2 as i8)
+}
+
+impl GlvParams {
+    /// Discovers `(beta, lambda)` for `curve`'s subgroup of order `n`
+    /// generated by `base`, then derives the short lattice basis used by
+    /// `decompose`. Returns `None` when no nontrivial cube root of unity
+    /// witnessing the endomorphism exists for this curve/order pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use curves::glv::{GlvCurve, GlvParams};
+    ///
+    /// fn main() {
+    ///     let curve = GlvCurve::default();
+    ///     let params = GlvParams::discover(&curve, curve.base(), 21).unwrap();
+    ///     println!("{:?}", params);
+    /// }
+    /// ```
+    pub fn discover(curve: &GlvCurve, base: Point, n: i8) -> Option<GlvParams> {
+        let beta = cube_root_of_unity(curve.p)?;
+        let lambda = (2..n).find(|&candidate| {
+            if ((candidate as i32).pow(3)).rem_euclid(n as i32) != 1 {
+                return false;
+            }
+            let phi_base = Point {
+                x: field_mul(beta, base.x, curve.p),
+                y: base.y,
+                infinity: false,
+            };
+            curve.scalar_mul(base, candidate, n) == phi_base
+        })?;
+
+        let (a1, b1, a2, b2) = reduce_lattice(n, lambda);
+        Some(GlvParams {
+            p: curve.p,
+            beta,
+            lambda,
+            a1,
+            b1,
+            a2,
+            b2,
+        })
+    }
+}
+
+/// Applies the curve endomorphism `phi(x, y) = (beta*x mod p, y)`.
+pub fn endomorphism(point: Point, params: GlvParams) -> Point {
+    Point {
+        x: field_mul(params.beta, point.x, params.p),
+        y: point.y,
+        infinity: point.infinity,
+    }
+}
+
+/// Splits `k` into `(k1, k2)` with `k = k1 + k2*lambda (mod n)`, each
+/// bounded by roughly `sqrt(n)` regardless of how large `k` is, per GECC
+/// Algorithm 3.74.
+pub fn decompose(k: i8, n: i8, params: GlvParams) -> (i8, i8) {
+    let (k, n) = (k as i32, n as i32);
+    let (a1, b1, a2, b2) = (
+        params.a1 as i32,
+        params.b1 as i32,
+        params.a2 as i32,
+        params.b2 as i32,
+    );
+
+    // round-to-nearest division that is correct for negative numerators too
+    let round_div = |num: i32, den: i32| (2 * num + den).div_euclid(2 * den);
+    let c1 = round_div(b2 * k, n);
+    let c2 = round_div(-b1 * k, n);
+
+    let k1 = k - c1 * a1 - c2 * a2;
+    let k2 = -c1 * b1 - c2 * b2;
+    (k1 as i8, k2 as i8)
+}
+
+/// Computes `k*point` as `k1*point + k2*phi(point)` with two interleaved
+/// half-width multiplications instead of one full-width ladder.
+///
+/// # Examples
+///
+/// ```rust
+/// use curves::glv::{decompose, endomorphism, scalar_mul_glv, GlvCurve, GlvParams};
+///
+/// fn main() {
+///     let curve = GlvCurve::default();
+///     let base = curve.base();
+///     let n = 21;
+///     let params = GlvParams::discover(&curve, base, n).unwrap();
+///
+///     // scalar_mul_glv agrees with plain double-and-add scalar_mul for
+///     // every scalar in range, and each decomposed half is short.
+///     for k in 0..n {
+///         assert_eq!(
+///             scalar_mul_glv(&curve, base, k, n, params),
+///             curve.scalar_mul(base, k, n)
+///         );
+///         let (k1, k2) = decompose(k, n, params);
+///         assert!((k1 as i32).abs() <= (n / 2) as i32 && (k2 as i32).abs() <= (n / 2) as i32);
+///     }
+///
+///     let phi_base = endomorphism(base, params);
+///     assert_eq!(phi_base, curve.scalar_mul(base, params.lambda, n));
+/// }
+/// ```
+pub fn scalar_mul_glv(curve: &GlvCurve, point: Point, k: i8, n: i8, params: GlvParams) -> Point {
+    let (k1, k2) = decompose(k, n, params);
+    let phi_point = endomorphism(point, params);
+
+    // Fold each half's sign into its point, then share one doubling chain.
+    let negate = |pt: Point| Point { y: field_mul(pt.y, -1, curve.p), ..pt };
+    let p1 = if k1 < 0 { negate(point) } else { point };
+    let p2 = if k2 < 0 { negate(phi_point) } else { phi_point };
+    let (a, b) = (k1.unsigned_abs(), k2.unsigned_abs());
+    let mut result = INFINITY;
+    for bit in (0..8 - (a | b).leading_zeros()).rev() {
+        result = curve.scalar_add(result, result);
+        if (a >> bit) & 1 == 1 { result = curve.scalar_add(result, p1); }
+        if (b >> bit) & 1 == 1 { result = curve.scalar_add(result, p2); }
+    }
+    result
+}
@@ -2,6 +2,7 @@
 // Code is licensed with BSD
 
 use algebra::arith;
+use algebra::hash;
 use curves::clockcurve;
 use subgroups::subgroup;
 
@@ -28,7 +29,7 @@ impl ECDSA {
     }
 
     pub fn hash(&self, message: i8) -> i8 {
-        message
+        hash::hash(&[message])
     }
 
     /// Returns signature with the param(message, private, random nonce).
@@ -104,4 +105,63 @@ impl ECDSA {
         // check r == ((z/s)*G + (r/s)*P).x
         self.group.curve.scalar_add(p1, p2).x == r
     }
+
+    /// Recovers the signer's public key from a signature, so a verifier can
+    /// check a signature without the signer ever having transmitted `pubkey`.
+    ///
+    /// `recovery_id`'s low bit chooses which of the (up to two) points sharing
+    /// the x-coordinate `r` is the nonce point `R`; its second bit asks for the
+    /// high-x candidate `r + order`, for the rare case where the group order
+    /// lets an x-coordinate alias across the field. Returns `None` when no
+    /// point in the group has a matching x-coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::ecdsa;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let private = 5;
+    ///    let randomk = 7;
+    ///    let ecd = ecdsa::ECDSA::new();
+    ///    let (r, s) = ecd.sign(message, private, randomk);
+    ///    let recovered = ecd.recover(message, r, s, 0);
+    ///    println!("recovered pubkey: {:?}", recovered);
+    /// }
+    pub fn recover(
+        &self,
+        message: i8,
+        r: i8,
+        s: i8,
+        recovery_id: u8,
+    ) -> Option<clockcurve::Point> {
+        let n = self.group.order();
+        let z = self.hash(message);
+
+        // A second recovery-id bit would lift r back to r + n if the x-coordinate
+        // had been reduced mod n but still needs the high candidate on the curve.
+        let x_candidate = if recovery_id & 0b10 != 0 { r + n } else { r };
+
+        // The group is tiny, so rather than solving the curve equation for y
+        // directly we scan it for the (at most two) points sharing this
+        // x-coordinate; the low recovery-id bit then picks between them.
+        let mut candidates: Vec<clockcurve::Point> = (0..n)
+            .map(|k| self.group.scalar_basemul(k))
+            .filter(|point| point.x == x_candidate)
+            .collect();
+        candidates.sort_by_key(|point| point.y);
+        let r_point = if recovery_id & 0b1 != 0 {
+            candidates.pop()
+        } else {
+            candidates.into_iter().next()
+        }?;
+
+        // Q = r_inv * (s*R - z*G)
+        let r_inv = arith::mod_div(1, r, n);
+        let sr = self.group.curve.scalar_mul(r_point, s);
+        let neg_zg = self.group.scalar_basemul(arith::mod_mul(z, n - 1, n));
+        let combined = self.group.curve.scalar_add(sr, neg_zg);
+        Some(self.group.curve.scalar_mul(combined, r_inv))
+    }
 }
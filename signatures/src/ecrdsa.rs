@@ -0,0 +1,126 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::arith;
+use curves::clockcurve;
+use subgroups::subgroup;
+
+/// EC-RDSA (GOST R 34.10), following the Linux kernel's `ecrdsa` module.
+///
+/// Structurally it is close to `ECDSA`, but two details flip: the signature
+/// equation divides by the hash `z` rather than by `s`, and `s` mixes the
+/// secret and nonce *additively* (`s = r*private + k*z`) instead of through
+/// a modular inverse of the nonce.
+#[derive(Debug, Clone, Copy)]
+pub struct EcRDSA {
+    pub group: subgroup::SubGroup,
+}
+
+impl Default for EcRDSA {
+    fn default() -> Self {
+        EcRDSA::new()
+    }
+}
+
+impl EcRDSA {
+    pub fn new() -> Self {
+        EcRDSA {
+            group: subgroup::SubGroup::default(),
+        }
+    }
+
+    pub fn pubkey(&self, pk: i8) -> clockcurve::Point {
+        self.group.curve.scalar_basemul(pk)
+    }
+
+    pub fn hash(&self, message: i8) -> i8 {
+        message
+    }
+
+    /// Returns signature with the param(message, private, random nonce).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::ecrdsa;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let private = 5;
+    ///    let randomk = 7;
+    ///    let ec = ecrdsa::EcRDSA::new();
+    ///    let (r, s) = ec.sign(message, private, randomk);
+    ///    println!("signature: r:{},s:{}", r, s);
+    /// }
+    pub fn sign(&self, message: i8, private: i8, randomk: i8) -> (i8, i8) {
+        let q = self.group.order();
+
+        // z = hash(message) mod q, substituting 1 when the hash reduces to 0
+        // (as GOST R 34.10 requires, since z is later inverted)
+        let z = match self.hash(message).rem_euclid(q) {
+            0 => 1,
+            z => z,
+        };
+
+        // r = (k*G).x mod q
+        let r = self.group.scalar_basemul(randomk).x.rem_euclid(q);
+
+        // s = r*private + k*z
+        let s = arith::mod_add(
+            arith::mod_mul(r, private, q),
+            arith::mod_mul(randomk, z, q),
+            q,
+        );
+        (r, s)
+    }
+
+    /// Returns verify result for `(r, s)` over hash `z = hash(message)`.
+    ///
+    /// Rejects signatures with `r` or `s` outside `1..q`, then checks
+    /// `C.x mod q == r` for `C = z1*G + z2*pubkey`, with `z1 = s/z` and
+    /// `z2 = -r/z` — the hash is inverted here, where `ECDSA::verify`
+    /// inverts `s` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::ecrdsa;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let private = 5;
+    ///    let randomk = 7;
+    ///    let ec = ecrdsa::EcRDSA::new();
+    ///    let (r, s) = ec.sign(message, private, randomk);
+    ///    let pubkey = ec.pubkey(private);
+    ///    assert!(ec.verify(message, pubkey, r, s));
+    /// }
+    pub fn verify(&self, message: i8, pubkey: clockcurve::Point, r: i8, s: i8) -> bool {
+        let q = self.group.order();
+
+        if r <= 0 || r >= q || s <= 0 || s >= q {
+            return false;
+        }
+
+        // z = hash(message) mod q, substituting 1 when the hash reduces to 0
+        // (as GOST R 34.10 requires, since z is inverted below)
+        let z = match self.hash(message).rem_euclid(q) {
+            0 => 1,
+            z => z,
+        };
+
+        // v = 1/z
+        let v = arith::mod_div(1, z, q);
+        // z1 = s*v
+        let z1 = arith::mod_mul(s, v, q);
+        // z2 = -r*v == (q - r)*v
+        let z2 = arith::mod_mul(q - r, v, q);
+
+        // C = z1*G + z2*pubkey
+        let p1 = self.group.scalar_basemul(z1);
+        let p2 = self.group.curve.scalar_mul(pubkey, z2);
+        let c = self.group.curve.scalar_add(p1, p2);
+
+        c.x.rem_euclid(q) == r
+    }
+}
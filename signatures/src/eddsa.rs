@@ -0,0 +1,98 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::arith;
+use algebra::hash;
+use curves::edwards;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EdDSA {
+    pub curve: edwards::Edwards,
+}
+
+impl Default for EdDSA {
+    fn default() -> Self {
+        EdDSA::new()
+    }
+}
+
+impl EdDSA {
+    pub fn new() -> Self {
+        EdDSA {
+            curve: edwards::Edwards::default(),
+        }
+    }
+
+    pub fn pubkey(&self, secret: i8) -> edwards::Point {
+        self.curve.scalar_basemul(secret)
+    }
+
+    /// Challenge binding the commitment point, the public key and the
+    /// message, exactly like `Schnorr::challenge`.
+    pub fn challenge(&self, r: edwards::Point, pubkey: edwards::Point, message: i8) -> i8 {
+        hash::hash(&[r.x, pubkey.x, message]).rem_euclid(self.curve.order())
+    }
+
+    /// Returns signature (R, S) for the param(message, secret).
+    ///
+    /// Unlike `ECDSA::sign` and `Schnorr::sign` there is no external
+    /// `randomk`/`nonce` argument: the nonce is derived deterministically
+    /// from `secret` and `message`, so reusing it across two different
+    /// messages can never leak the secret the way an accidentally-reused
+    /// ECDSA/Schnorr nonce would.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::eddsa;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let secret = 5;
+    ///    let ed = eddsa::EdDSA::new();
+    ///    let (r, s) = ed.sign(message, secret);
+    ///    println!("signature: r:{:?},s:{}", r, s);
+    /// }
+    pub fn sign(&self, message: i8, secret: i8) -> (edwards::Point, i8) {
+        let n = self.curve.order();
+
+        // r = hash(secret, message) mod n
+        let nonce = hash::hash(&[secret, message]).rem_euclid(n);
+
+        // R = r*B
+        let r = self.curve.scalar_basemul(nonce);
+
+        // e = hash(R.x, A.x, message)
+        let pubkey = self.pubkey(secret);
+        let e = self.challenge(r, pubkey, message);
+
+        // S = r + e*secret
+        let s = arith::mod_add(nonce, arith::mod_mul(e, secret, n), n);
+        (r, s)
+    }
+
+    /// Returns verify result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::eddsa;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let secret = 5;
+    ///    let ed = eddsa::EdDSA::new();
+    ///    let (r, s) = ed.sign(message, secret);
+    ///    let pubkey = ed.pubkey(secret);
+    ///    assert!(ed.verify(message, pubkey, r, s));
+    /// }
+    pub fn verify(&self, message: i8, pubkey: edwards::Point, r: edwards::Point, s: i8) -> bool {
+        let e = self.challenge(r, pubkey, message);
+
+        // check S*B == R + e*A
+        let sb = self.curve.scalar_basemul(s);
+        let ea = self.curve.scalar_mul(pubkey, e);
+        let rhs = self.curve.scalar_add(r, ea);
+        sb == rhs
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::arith;
+use algebra::hash;
+use curves::clockcurve;
+use subgroups::subgroup;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Schnorr {
+    pub group: subgroup::SubGroup,
+}
+
+impl Default for Schnorr {
+    fn default() -> Self {
+        Schnorr::new()
+    }
+}
+
+impl Schnorr {
+    pub fn new() -> Self {
+        Schnorr {
+            group: subgroup::SubGroup::default(),
+        }
+    }
+
+    pub fn pubkey(&self, pk: i8) -> clockcurve::Point {
+        self.group.curve.scalar_basemul(pk)
+    }
+
+    /// Challenge binding the commitment point, the public key and the
+    /// message, rather than the message alone, unlike `ECDSA::hash`.
+    pub fn challenge(&self, r: clockcurve::Point, pubkey: clockcurve::Point, message: i8) -> i8 {
+        hash::hash(&[r.x, pubkey.x, message]).rem_euclid(self.group.order())
+    }
+
+    /// Returns signature (R, s) for the param(message, private, nonce).
+    ///
+    /// Unlike `ECDSA::sign` this never inverts the nonce: `s` is linear in
+    /// `private`, which is exactly what makes Schnorr signatures amenable to
+    /// threshold/FROST-style construction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::schnorr;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let private = 5;
+    ///    let nonce = 7;
+    ///    let sch = schnorr::Schnorr::new();
+    ///    let (r, s) = sch.sign(message, private, nonce);
+    ///    println!("signature: r:{:?},s:{}", r, s);
+    /// }
+    pub fn sign(&self, message: i8, private: i8, nonce: i8) -> (clockcurve::Point, i8) {
+        let m = self.group.order();
+
+        // R = nonce*G
+        let r = self.group.scalar_basemul(nonce);
+
+        // e = hash(R.x, pubkey, message)
+        let pubkey = self.pubkey(private);
+        let e = self.challenge(r, pubkey, message);
+
+        // s = nonce + e*private
+        let s = arith::mod_add(nonce, arith::mod_mul(e, private, m), m);
+        (r, s)
+    }
+
+    /// Returns verify result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::schnorr;
+    ///
+    /// fn main() {
+    ///    let message = 10;
+    ///    let private = 5;
+    ///    let nonce = 7;
+    ///    let sch = schnorr::Schnorr::new();
+    ///    let (r, s) = sch.sign(message, private, nonce);
+    ///    let pubkey = sch.pubkey(private);
+    ///    assert!(sch.verify(message, pubkey, r, s));
+    /// }
+    pub fn verify(&self, message: i8, pubkey: clockcurve::Point, r: clockcurve::Point, s: i8) -> bool {
+        let e = self.challenge(r, pubkey, message);
+
+        // check s*G == R + e*pubkey
+        let sg = self.group.scalar_basemul(s);
+        let e_pubkey = self.group.curve.scalar_mul(pubkey, e);
+        let rhs = self.group.curve.scalar_add(r, e_pubkey);
+        sg.x == rhs.x && sg.y == rhs.y
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) BohuTANG
+// Code is licensed with BSD
+
+use algebra::arith;
+use subgroups::subgroup;
+
+use crate::ecdsa;
+
+/// One party's Shamir share of a secret: the polynomial's value at `index`.
+#[derive(Debug, Clone, Copy)]
+pub struct Share {
+    pub index: i8,
+    pub value: i8,
+}
+
+impl Share {
+    /// Adds two shares held by the same party (same `index`) without ever
+    /// reconstructing either secret, mirroring OpenEthereum SecretStore's
+    /// additive `Secret::zero` identity: summing in a party's contribution
+    /// `Share::zero(index)` leaves the running total unchanged, so a round
+    /// that collects nothing from one party still combines correctly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self.index != other.index`; shares of different parties
+    /// cannot be summed directly, only recombined via `Threshold::combine`.
+    ///
+    /// # Examples
+    ///
+    /// Two dealers each Shamir-share their own secret; every party sums the
+    /// two shares it received before `combine` reconstructs
+    /// `secret_a + secret_b` directly, without either secret ever being
+    /// assembled on its own.
+    ///
+    /// ```rust
+    /// use signatures::threshold;
+    ///
+    /// fn main() {
+    ///    let th = threshold::Threshold::new();
+    ///    let m = th.group.order();
+    ///
+    ///    let shares_a = th.split(5, 3, &[3]);
+    ///    let shares_b = th.split(9, 3, &[2]);
+    ///
+    ///    let summed: Vec<_> = shares_a
+    ///        .iter()
+    ///        .zip(shares_b.iter())
+    ///        .map(|(a, b)| a.add(b, m))
+    ///        .collect();
+    ///
+    ///    let total = th.combine(&summed[..2]);
+    ///    println!("secret_a + secret_b = {}", total);
+    /// }
+    /// ```
+    pub fn add(&self, other: &Share, m: i8) -> Share {
+        assert_eq!(self.index, other.index, "shares must share a party index");
+        Share {
+            index: self.index,
+            value: arith::mod_add(self.value, other.value, m),
+        }
+    }
+
+    /// The additive identity for `index`: summing this into a running total
+    /// via `add` leaves it unchanged.
+    pub fn zero(index: i8) -> Self {
+        Share { index, value: 0 }
+    }
+}
+
+fn neg(v: i8, m: i8) -> i8 {
+    arith::mod_mul(v, m - 1, m)
+}
+
+fn sub(a: i8, b: i8, m: i8) -> i8 {
+    arith::mod_add(a, neg(b, m), m)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Threshold {
+    pub group: subgroup::SubGroup,
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Threshold::new()
+    }
+}
+
+impl Threshold {
+    pub fn new() -> Self {
+        Threshold {
+            group: subgroup::SubGroup::default(),
+        }
+    }
+
+    /// Splits `private` into `n` Shamir shares reconstructable by any `t` of
+    /// them, modeled on OpenEthereum SecretStore's threshold key generation.
+    ///
+    /// Like every other random value in this crate (see `ECDSA::sign`'s
+    /// `randomk`), the polynomial's randomness is supplied by the caller
+    /// rather than drawn internally: `coeffs` must hold exactly `t - 1`
+    /// random non-constant coefficients, with `private` as the constant term.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use signatures::threshold;
+    ///
+    /// fn main() {
+    ///    let th = threshold::Threshold::new();
+    ///    let private = 5;
+    ///    let coeffs = [3]; // degree-1 polynomial -> 2-of-n
+    ///    let shares = th.split(private, 3, &coeffs);
+    ///    let recombined = th.combine(&shares[..2]);
+    ///    println!("recombined: {}", recombined);
+    /// }
+    pub fn split(&self, private: i8, n: i8, coeffs: &[i8]) -> Vec<Share> {
+        let m = self.group.order();
+
+        (1..=n)
+            .map(|index| {
+                let mut value = private;
+                let mut power = index;
+                for &c in coeffs {
+                    value = arith::mod_add(value, arith::mod_mul(c, power, m), m);
+                    power = arith::mod_mul(power, index, m);
+                }
+                Share { index, value }
+            })
+            .collect()
+    }
+
+    /// Recombines any `t`-sized subset of shares via Lagrange interpolation
+    /// of the sharing polynomial at `x = 0`.
+    pub fn combine(&self, shares: &[Share]) -> i8 {
+        let m = self.group.order();
+
+        shares.iter().fold(0i8, |acc, share| {
+            let (mut num, mut den) = (1i8, 1i8);
+            for other in shares {
+                if other.index == share.index {
+                    continue;
+                }
+                num = arith::mod_mul(num, neg(other.index, m), m);
+                den = arith::mod_mul(den, sub(share.index, other.index, m), m);
+            }
+            let lagrange = arith::mod_mul(num, arith::mod_div(1, den, m), m);
+            arith::mod_add(acc, arith::mod_mul(share.value, lagrange, m), m)
+        })
+    }
+
+    /// Reconstructs the signing secret and nonce through Lagrange
+    /// interpolation and emits a standard (r, s) pair, verifiable by the
+    /// existing `ECDSA::verify` exactly as if one party had signed alone.
+    pub fn sign_distributed(
+        &self,
+        shares: &[Share],
+        message: i8,
+        nonce_shares: &[Share],
+    ) -> (i8, i8) {
+        let private = self.combine(shares);
+        let nonce = self.combine(nonce_shares);
+
+        let ecd = ecdsa::ECDSA { group: self.group };
+        ecd.sign(message, private, nonce)
+    }
+}